@@ -1,24 +1,27 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt;
-use std::path::Path;
-use std::sync::Arc;
-use std::sync::Mutex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use cargo::core::{Workspace, source::SourceId};
-use cargo::util::{config::Config, important_paths::find_root_manifest_for_wd, toml::read_manifest, OptVersionReq, VersionExt};
+use cargo::util::{config::Config, important_paths::find_root_manifest_for_wd, toml::read_manifest, OptVersionReq};
 
 use anyhow::{Context, Result};
 use curl::easy::Easy;
 use rayon::prelude::*;
 use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
-#[derive(Debug, Deserialize)]
+use toml_edit::{value, Document};
+
+const DEPENDENCY_TABLES: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+#[derive(Debug, Deserialize, Serialize)]
 pub struct CrateVersions {
     versions: Vec<CratesResp>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct CratesResp {
     id: u64,
     #[serde(rename = "crate")]
@@ -38,7 +41,7 @@ pub struct CratesResp {
     audit_actions: Option<Vec<AuditActions>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct User {
     id: u64,
     login: String,
@@ -47,26 +50,52 @@ pub struct User {
     url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct AuditActions {
     action: Option<String>,
     user: User,
     time: String,
 }
 
-#[derive(Debug)]
+// `version: None` means "no release could be found on this channel" (an
+// empty/unreachable registry, a git remote with no semver-looking tags,
+// ...) -- it must NOT be confused with an actual version, so callers have
+// to unwrap it explicitly and skip the dependency rather than silently
+// reporting a fake `0.0.0`.
+#[derive(Debug, Default)]
 pub struct CratesIoResp {
-    crate_name: String,
-    version: Version,
-    last_updated: String,
+    version: Option<Version>,
+    // newest version that still satisfies the dependency's declared
+    // `VersionReq`, as opposed to `version` which is the newest overall
+    compatible_version: Option<Version>,
 }
 
-impl Default for CratesIoResp {
-    fn default() -> CratesIoResp {
-        CratesIoResp {
-            crate_name: String::new(),
-            version: Version::new(0, 0, 0),
-            last_updated: String::new(),
+#[derive(Debug, Deserialize)]
+struct CargoLock {
+    package: Vec<LockedPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LockedPackage {
+    name: String,
+    version: String,
+    source: Option<String>,
+}
+
+// A minimal, registry-agnostic version record: enough for channel/semver
+// selection and for caching, regardless of whether it came from the
+// crates.io web API or a sparse-index registry's own JSON-lines format.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct VersionEntry {
+    num: String,
+    yanked: bool,
+}
+
+impl From<&CratesResp> for VersionEntry {
+    fn from(resp: &CratesResp) -> VersionEntry {
+        VersionEntry {
+            num: resp.num.clone(),
+            yanked: resp.yanked,
         }
     }
 }
@@ -82,7 +111,20 @@ pub struct Dep {
 struct OutdatedDependency {
     dependency_name: String,
     version_in_toml: String,
-    latest_version: String,
+    // version actually resolved in Cargo.lock
+    resolved: String,
+    // newest version satisfying `version_in_toml`
+    compatible: String,
+    // absolute newest version on the tracked channel
+    latest: String,
+}
+
+impl OutdatedDependency {
+    // compatible == latest == resolved means the lockfile is already sitting
+    // on the newest version the declared requirement allows for
+    fn is_up_to_date(&self) -> bool {
+        self.resolved == self.compatible && self.compatible == self.latest
+    }
 }
 
 unsafe impl Send for OutdatedDependency {}
@@ -107,22 +149,20 @@ impl fmt::Display for CrateOutdated {
         for (crate_name, outdated_dep) in self.outdated.iter() {
             output_string += &format!("{}\n", crate_name);
             for (dep_num, out_dep) in outdated_dep.iter().enumerate() {
-                if dep_num == 0 && outdated_dep.len() > 1 {
-                    output_string += &format!(
-                        "\t├── {}: {} -> {}\n",
-                        out_dep.dependency_name, out_dep.version_in_toml, out_dep.latest_version
-                    );
-                } else if dep_num > 0 && dep_num != outdated_dep.len() - 1 {
-                    output_string += &format!(
-                        "\t├── {}: {} -> {}\n",
-                        out_dep.dependency_name, out_dep.version_in_toml, out_dep.latest_version
-                    );
+                let branch = if dep_num == outdated_dep.len() - 1 {
+                    "└──"
                 } else {
-                    output_string += &format!(
-                        "\t└── {}: {} -> {}\n",
-                        out_dep.dependency_name, out_dep.version_in_toml, out_dep.latest_version
-                    );
-                }
+                    "├──"
+                };
+                output_string += &format!(
+                    "\t{} {}: project {}, resolved {}, compatible {}, latest {}\n",
+                    branch,
+                    out_dep.dependency_name,
+                    out_dep.version_in_toml,
+                    out_dep.resolved,
+                    out_dep.compatible,
+                    out_dep.latest
+                );
             }
         }
         write!(f, "{}", output_string)
@@ -160,16 +200,144 @@ fn check_for_workspace_members(ws: cargo::core::Workspace) -> HashMap<String, Ha
     deps
 }
 
-fn is_up_to_date(ver_req: &VersionReq, latest: &Version) -> bool {
-    ver_req.matches(&latest)
+// "stable" for no pre-release identifier, otherwise the leading
+// dot-separated identifier of the pre-release (e.g. `1.0.0-beta.2` is on
+// the `beta` channel).
+fn channel_from_pre(pre: &semver::Prerelease) -> String {
+    if pre.is_empty() {
+        "stable".to_string()
+    } else {
+        pre.as_str().split('.').next().unwrap_or("stable").to_string()
+    }
+}
+
+fn channel_for_version(version: &Version) -> String {
+    channel_from_pre(&version.pre)
+}
+
+// Figures out which channel a dependency is currently tracking by looking at
+// the pre-release identifier of its `VersionReq`'s first comparator, unless
+// the caller passed an explicit override (`--pre`/`--channel`).
+fn tracked_channel(version_req: &VersionReq, override_channel: Option<&str>) -> String {
+    if let Some(channel) = override_channel {
+        return channel.to_string();
+    }
+
+    version_req
+        .comparators
+        .first()
+        .map(|comparator| channel_from_pre(&comparator.pre))
+        .unwrap_or_else(|| "stable".to_string())
+}
+
+const CRATES_IO_API_BASE: &str = "https://crates.io";
+
+// Where and how long cached registry responses are kept. `dir` is `None`
+// when caching is disabled entirely.
+#[derive(Debug, Clone)]
+struct CacheOptions {
+    dir: Option<PathBuf>,
+    ttl: Duration,
+    offline: bool,
 }
 
-fn get_latest_from_repo(crate_name: String) -> Result<CratesIoResp> {
-    let build_url = format!("https://crates.io/api/v1/crates/{}/versions", crate_name);
+// On-disk form of a cached `versions` response, timestamped so we can
+// tell whether it's still within the TTL.
+#[derive(Debug, Deserialize)]
+struct CachedVersions {
+    fetched_at_secs: u64,
+    versions: Vec<VersionEntry>,
+}
+
+#[derive(Debug, Serialize)]
+struct CachedVersionsRef<'a> {
+    fetched_at_secs: u64,
+    versions: &'a [VersionEntry],
+}
+
+fn default_cache_dir() -> Option<PathBuf> {
+    if let Some(cargo_home) = std::env::var_os("CARGO_HOME") {
+        return Some(PathBuf::from(cargo_home).join("outdated-cache"));
+    }
+    dirs::cache_dir().map(|dir| dir.join("cargo-outdated"))
+}
+
+// A crate name alone isn't a unique cache key -- the same name can be
+// served by crates.io and by one or more alternative registries with
+// completely different version histories. Hash `(registry_id, crate_name)`
+// together so those don't collide on disk, while keeping the crate name
+// in the filename for human debugging.
+fn cache_key(registry_id: &str, crate_name: &str) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    registry_id.hash(&mut hasher);
+    crate_name.hash(&mut hasher);
+    format!("{:016x}-{}", hasher.finish(), crate_name)
+}
+
+fn cache_file_path(cache_dir: &Path, registry_id: &str, crate_name: &str) -> PathBuf {
+    cache_dir.join(format!("{}.json", cache_key(registry_id, crate_name)))
+}
+
+fn read_from_cache(
+    cache_dir: &Path,
+    registry_id: &str,
+    crate_name: &str,
+    ttl: Duration,
+) -> Option<Vec<VersionEntry>> {
+    let raw = fs::read_to_string(cache_file_path(cache_dir, registry_id, crate_name)).ok()?;
+    let cached: CachedVersions = serde_json::from_str(&raw).ok()?;
+
+    let now_secs = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    if now_secs.saturating_sub(cached.fetched_at_secs) > ttl.as_secs() {
+        return None;
+    }
+
+    Some(cached.versions)
+}
+
+fn write_to_cache(
+    cache_dir: &Path,
+    registry_id: &str,
+    crate_name: &str,
+    versions: &[VersionEntry],
+) -> Result<()> {
+    fs::create_dir_all(cache_dir)
+        .with_context(|| format!("Error creating cache dir {}", cache_dir.display()))?;
+
+    let fetched_at_secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .context("Error reading system time")?
+        .as_secs();
+    let cached = CachedVersionsRef {
+        fetched_at_secs,
+        versions,
+    };
+
+    let path = cache_file_path(cache_dir, registry_id, crate_name);
+    fs::write(&path, serde_json::to_string(&cached)?)
+        .with_context(|| format!("Error writing cache file {}", path.display()))
+}
+
+fn clear_cache(cache_dir: &Path) -> Result<()> {
+    if cache_dir.exists() {
+        fs::remove_dir_all(cache_dir)
+            .with_context(|| format!("Error clearing cache dir {}", cache_dir.display()))?;
+    }
+    Ok(())
+}
+
+// Performs a single HTTP GET, erroring explicitly on non-200 responses
+// instead of handing the error body to the caller's parser and getting a
+// confusing parse failure. Shared by every registry protocol we speak
+// (crates.io's web API, the sparse-index JSON-lines format, ...).
+fn fetch_raw_body(build_url: &str) -> Result<String> {
     let mut data = Vec::new();
     let mut handle = Easy::new();
 
-    handle.url(&build_url).context("Error building URL")?;
+    handle.url(build_url).context("Error building URL")?;
     handle
         .useragent("Cargo Outdated Bot")
         .context("Error adding user-agent to curl")?;
@@ -185,30 +353,275 @@ fn get_latest_from_repo(crate_name: String) -> Result<CratesIoResp> {
         transfer.perform().context("Error reaching network")?;
     }
 
+    let status = handle.response_code().context("Error reading HTTP status")?;
     let resp_string = String::from_utf8(data).context("Error parsing response into string")?;
-    let versions: CrateVersions =
-        serde_json::from_str(&resp_string).context("Error deserializing")?;
+
+    if status != 200 {
+        anyhow::bail!("{} returned HTTP {}: {}", build_url, status, resp_string);
+    }
+
+    Ok(resp_string)
+}
+
+// Retries transient network failures a handful of times with a growing
+// backoff before giving up.
+fn fetch_raw_body_with_retry(build_url: &str) -> Result<String> {
+    const MAX_ATTEMPTS: u32 = 3;
+
+    let mut attempt = 1;
+    loop {
+        match fetch_raw_body(build_url) {
+            Ok(body) => return Ok(body),
+            Err(_) if attempt < MAX_ATTEMPTS => {
+                std::thread::sleep(Duration::from_millis(200 * 2u64.pow(attempt - 1)));
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// Wraps any registry-specific fetch closure with the shared on-disk cache:
+// serves a fresh cache entry without touching the network, never touches
+// the network at all when `cache.offline` is set (falling back to an
+// empty listing, which `select_latest` turns into an explicit "unknown"
+// rather than a fake `0.0.0`), and otherwise calls through and caches the
+// result. `registry_id` disambiguates the cache key so two registries
+// serving the same crate name don't collide.
+fn fetch_versions_cached(
+    registry_id: &str,
+    crate_name: &str,
+    cache: &CacheOptions,
+    fetch: impl FnOnce() -> Result<Vec<VersionEntry>>,
+) -> Result<Vec<VersionEntry>> {
+    if let Some(cache_dir) = &cache.dir {
+        if let Some(cached) = read_from_cache(cache_dir, registry_id, crate_name, cache.ttl) {
+            return Ok(cached);
+        }
+    }
+
+    if cache.offline {
+        return Ok(Vec::new());
+    }
+
+    let versions = fetch()?;
+
+    if let Some(cache_dir) = &cache.dir {
+        if let Err(e) = write_to_cache(cache_dir, registry_id, crate_name, &versions) {
+            eprintln!("Warning: failed to cache {}: {}", crate_name, e);
+        }
+    }
+
+    Ok(versions)
+}
+
+// Picks the "latest" (newest on the tracked channel) and "compatible"
+// (newest matching `version_req`) versions out of a versions listing.
+// `latest.version` stays `None` when nothing on the tracked channel is
+// found, rather than silently defaulting to `0.0.0`.
+fn select_latest(versions: &[VersionEntry], channel: &str, version_req: &VersionReq) -> Result<CratesIoResp> {
     let mut latest = CratesIoResp::default();
 
-    for version in versions.versions.iter().rev() {
+    for version in versions.iter() {
         //get the latest version for this crate, unless the version was yanked, skip that version
         if version.yanked {
             continue;
-        } else {
-            if Version::parse(&version.num).context("Error parsing version")?.is_prerelease() {
-                
-            } 
-            latest = CratesIoResp {
-                crate_name: version.crate_name.clone(),
-                version: Version::parse(&version.num).context("Error parsing version")?,
-                last_updated: version.updated_at.split('.').collect::<Vec<&str>>()[0].to_string(),
-            };
+        }
+
+        let parsed = Version::parse(&version.num).context("Error parsing version")?;
+
+        // the newest version satisfying the project's declared requirement,
+        // regardless of channel -- this is the "compatible" column
+        if version_req.matches(&parsed)
+            && latest
+                .compatible_version
+                .as_ref()
+                .is_none_or(|compatible| &parsed > compatible)
+        {
+            latest.compatible_version = Some(parsed.clone());
+        }
+
+        if channel_for_version(&parsed) != channel {
+            continue;
+        }
+
+        // never report a lower version as "latest" -- let semver's own
+        // ordering (which accounts for build metadata) decide
+        if latest.version.as_ref().is_none_or(|current| &parsed > current) {
+            latest.version = Some(parsed);
         }
     }
     Ok(latest)
 }
 
-fn create_cargo_manifest() -> Result<HashMap<String, HashSet<Dep>>> {
+fn get_latest_from_repo(
+    crate_name: String,
+    channel: &str,
+    version_req: &VersionReq,
+    cache: &CacheOptions,
+) -> Result<CratesIoResp> {
+    let versions = fetch_versions_cached(CRATES_IO_API_BASE, &crate_name, cache, || {
+        let build_url = format!("{}/api/v1/crates/{}/versions", CRATES_IO_API_BASE, crate_name);
+        let body = fetch_raw_body_with_retry(&build_url)?;
+        let resp: CrateVersions = serde_json::from_str(&body).context("Error deserializing")?;
+        Ok(resp.versions.iter().map(VersionEntry::from).collect())
+    })?;
+    select_latest(&versions, channel, version_req)
+}
+
+// One line of a sparse-index `/{name}` response: newline-delimited JSON,
+// one record per published version. We only need enough of the record to
+// feed `select_latest`; the rest (deps, cksum, features, ...) is Cargo's
+// concern, not ours.
+#[derive(Debug, Deserialize)]
+struct SparseIndexEntry {
+    vers: String,
+    yanked: bool,
+}
+
+impl From<&SparseIndexEntry> for VersionEntry {
+    fn from(entry: &SparseIndexEntry) -> VersionEntry {
+        VersionEntry {
+            num: entry.vers.clone(),
+            yanked: entry.yanked,
+        }
+    }
+}
+
+// Mirrors Cargo's own bucketing rule for where a crate's index file lives:
+// https://doc.rust-lang.org/cargo/reference/registries.html#index-format
+fn sparse_index_path(crate_name: &str) -> String {
+    let lower = crate_name.to_lowercase();
+    match lower.len() {
+        1 => format!("1/{}", lower),
+        2 => format!("2/{}", lower),
+        3 => format!("3/{}/{}", &lower[..1], lower),
+        _ => format!("{}/{}/{}", &lower[..2], &lower[2..4], lower),
+    }
+}
+
+fn parse_sparse_index_body(body: &str) -> Result<Vec<VersionEntry>> {
+    body.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let entry: SparseIndexEntry =
+                serde_json::from_str(line).context("Error deserializing sparse index entry")?;
+            Ok(VersionEntry::from(&entry))
+        })
+        .collect()
+}
+
+// Alternative registries speak Cargo's own index protocol rather than the
+// crates.io web API. We only know how to read the sparse-index flavor
+// (`sparse+https://...` source URLs, one JSON-lines file per crate); a
+// legacy git-index registry doesn't expose its version list over plain
+// HTTP at all, so there's nothing for us to fetch and we report unknown.
+fn get_latest_from_registry(
+    crate_name: String,
+    source_id: &SourceId,
+    channel: &str,
+    version_req: &VersionReq,
+    cache: &CacheOptions,
+) -> Result<CratesIoResp> {
+    if !source_id.is_sparse() {
+        return Ok(CratesIoResp::default());
+    }
+
+    let url = source_id.url().as_str();
+    let api_base = url.strip_prefix("sparse+").unwrap_or(url).trim_end_matches('/').to_string();
+
+    let versions = fetch_versions_cached(&api_base, &crate_name, cache, || {
+        let build_url = format!("{}/{}", api_base, sparse_index_path(&crate_name));
+        let body = fetch_raw_body_with_retry(&build_url)?;
+        parse_sparse_index_body(&body)
+    })?;
+    select_latest(&versions, channel, version_req)
+}
+
+// Lists the remote's tags and treats any tag that parses as a SemVer
+// version (optionally prefixed with `v`) as a release, reporting the
+// newest one. There is no "compatible" column for git deps since they
+// aren't resolved through a `VersionReq`. A remote with no tags that
+// parse as semver (e.g. pinned to a branch or a bare rev) has no
+// discoverable release, so we report `None` rather than claiming `0.0.0`
+// is somehow newer than whatever's checked out.
+fn get_latest_from_git(source_id: &SourceId, cache: &CacheOptions) -> Result<CratesIoResp> {
+    if cache.offline {
+        return Ok(CratesIoResp::default());
+    }
+
+    let url = source_id.url().to_string();
+
+    let mut remote = git2::Remote::create_detached(url.as_str()).context("Error creating git remote")?;
+    remote
+        .connect(git2::Direction::Fetch)
+        .context("Error connecting to git remote")?;
+
+    let mut latest_version: Option<Version> = None;
+    for head in remote.list().context("Error listing remote refs")?.iter() {
+        let tag = match head.name().strip_prefix("refs/tags/") {
+            Some(tag) => tag.trim_start_matches('v'),
+            None => continue,
+        };
+
+        let parsed = match Version::parse(tag) {
+            Ok(version) => version,
+            Err(_) => continue,
+        };
+
+        if latest_version.as_ref().is_none_or(|current| &parsed > current) {
+            latest_version = Some(parsed);
+        }
+    }
+
+    Ok(CratesIoResp {
+        version: latest_version,
+        compatible_version: None,
+    })
+}
+
+// Path dependencies aren't published anywhere -- the "latest" version is
+// just whatever the local crate's own Cargo.toml currently says.
+fn get_latest_from_path(source_id: &SourceId) -> Result<CratesIoResp> {
+    let manifest_path = source_id
+        .url()
+        .to_file_path()
+        .map(|dir| dir.join("Cargo.toml"))
+        .map_err(|_| anyhow::anyhow!("Error resolving path dependency directory"))?;
+
+    let raw = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Error reading manifest {}", manifest_path.display()))?;
+    let doc = raw
+        .parse::<Document>()
+        .with_context(|| format!("Error parsing manifest {}", manifest_path.display()))?;
+
+    let version_str = doc["package"]["version"]
+        .as_str()
+        .context("Error reading package version")?;
+
+    Ok(CratesIoResp {
+        version: Some(Version::parse(version_str).context("Error parsing path dependency version")?),
+        compatible_version: None,
+    })
+}
+
+// Dispatches to the right resolver for a dependency's source: crates.io,
+// another registry, a git remote, or a local path.
+fn resolve_latest(dep: &Dep, channel: &str, cache: &CacheOptions) -> Result<CratesIoResp> {
+    if dep.source_id.is_path() {
+        get_latest_from_path(&dep.source_id)
+    } else if dep.source_id.is_git() {
+        get_latest_from_git(&dep.source_id, cache)
+    } else if dep.source_id.is_crates_io() {
+        get_latest_from_repo(dep.name.clone(), channel, &dep.version_req, cache)
+    } else {
+        get_latest_from_registry(dep.name.clone(), &dep.source_id, channel, &dep.version_req, cache)
+    }
+}
+
+// Builds the `Config` every Cargo workspace operation in this file needs,
+// the same way the `cargo` binary itself would.
+fn cargo_config() -> Result<Config> {
     let mut config = match Config::default() {
         Ok(cfg) => cfg,
         Err(e) => {
@@ -217,10 +630,7 @@ fn create_cargo_manifest() -> Result<HashMap<String, HashSet<Dep>>> {
         }
     };
 
-    let cargo_home_path = match std::env::var_os("CARGO_HOME") {
-        Some(path) => Some(std::path::PathBuf::from(path)),
-        None => None,
-    };
+    let cargo_home_path = std::env::var_os("CARGO_HOME").map(std::path::PathBuf::from);
 
     config
         .configure(
@@ -236,10 +646,19 @@ fn create_cargo_manifest() -> Result<HashMap<String, HashSet<Dep>>> {
         )
         .context("Error creating Cargo config")?;
 
+    Ok(config)
+}
+
+// Finds and opens the workspace rooted at the current directory.
+fn open_workspace(config: &Config) -> Result<Workspace<'_>> {
     let manifest_path =
         find_root_manifest_for_wd(config.cwd()).context("Error getting manifest for project")?;
-    let curr_workspace =
-        Workspace::new(&manifest_path, &config).context("Error creating new workspace")?;
+    Workspace::new(&manifest_path, config).context("Error creating new workspace")
+}
+
+fn create_cargo_manifest() -> Result<HashMap<String, HashSet<Dep>>> {
+    let config = cargo_config()?;
+    let curr_workspace = open_workspace(&config)?;
     let source = SourceId::for_path(curr_workspace.root()).context("Error creating source")?;
     let manifest_cargo = Path::join(curr_workspace.root(), "Cargo.toml");
     let t = read_manifest(&manifest_cargo, source, curr_workspace.config());
@@ -251,7 +670,7 @@ fn create_cargo_manifest() -> Result<HashMap<String, HashSet<Dep>>> {
 
             let deps: HashSet<Dep> = real_manifest
                 .dependencies()
-                .into_iter()
+                .iter()
                 .map(|f| Dep {
                     name: f.name_in_toml().to_string(),
                     version_req:  match f.version_req().clone() {
@@ -272,9 +691,313 @@ fn create_cargo_manifest() -> Result<HashMap<String, HashSet<Dep>>> {
     })
 }
 
-fn main() -> Result<()> {
-    let deps = create_cargo_manifest()?;
+// How the report is printed: the human-readable tree, or JSON with
+// `--format json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Human,
+    Json,
+}
+
+// Cache entries older than this are re-requested from the registry.
+const DEFAULT_CACHE_TTL_SECS: u64 = 6 * 60 * 60;
+
+// Parsed command-line flags, hand-rolled; unrecognised arguments are ignored.
+struct Args {
+    upgrade: bool,
+    pinned_file: Option<PathBuf>,
+    channel_override: Option<String>,
+    format: OutputFormat,
+    exit_zero: bool,
+    offline: bool,
+    cache_ttl_secs: u64,
+    clear_cache: bool,
+}
+
+impl Args {
+    fn parse() -> Args {
+        let mut args = Args {
+            upgrade: false,
+            pinned_file: None,
+            channel_override: None,
+            format: OutputFormat::Human,
+            exit_zero: false,
+            offline: false,
+            cache_ttl_secs: DEFAULT_CACHE_TTL_SECS,
+            clear_cache: false,
+        };
+
+        let mut raw = std::env::args().skip(1).peekable();
+        if raw.peek().map(String::as_str) == Some("clear-cache") {
+            raw.next();
+            args.clear_cache = true;
+            return args;
+        }
+
+        while let Some(arg) = raw.next() {
+            match arg.as_str() {
+                "--upgrade" => args.upgrade = true,
+                "--pinned" => args.pinned_file = raw.next().map(PathBuf::from),
+                "--pre" | "--channel" => args.channel_override = raw.next(),
+                "--format" => {
+                    args.format = match raw.next().as_deref() {
+                        Some("json") => OutputFormat::Json,
+                        _ => OutputFormat::Human,
+                    }
+                }
+                "--exit-zero" => args.exit_zero = true,
+                "--offline" => args.offline = true,
+                "--cache-ttl" => {
+                    if let Some(secs) = raw.next().and_then(|s| s.parse().ok()) {
+                        args.cache_ttl_secs = secs;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        args
+    }
+
+    fn cache_options(&self) -> CacheOptions {
+        CacheOptions {
+            dir: default_cache_dir(),
+            ttl: Duration::from_secs(self.cache_ttl_secs),
+            offline: self.offline,
+        }
+    }
+}
+
+// Crates listed in the pinned file are never rewritten by `--upgrade`, one
+// crate name per line. Blank lines and `#`-prefixed comments are ignored.
+fn load_pinned_crates(path: &Path) -> Result<HashSet<String>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Error reading pinned crates file {}", path.display()))?;
 
+    Ok(contents
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.to_string())
+        .collect())
+}
+
+// Every Cargo.toml we might need to edit: the workspace root plus one per
+// member, mirroring how `check_for_workspace_members` walks `ws.members()`.
+fn manifest_paths_for_workspace(ws: &Workspace) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = ws.members().map(|p| p.manifest_path().to_path_buf()).collect();
+
+    let root_manifest = Path::join(ws.root(), "Cargo.toml");
+    if !paths.contains(&root_manifest) {
+        paths.push(root_manifest);
+    }
+
+    paths
+}
+
+// Opens the workspace the same way `create_cargo_manifest` does, but just
+// to collect the manifest paths `--upgrade` needs to rewrite.
+fn gather_manifest_paths() -> Result<Vec<PathBuf>> {
+    let config = cargo_config()?;
+    let curr_workspace = open_workspace(&config)?;
+
+    Ok(manifest_paths_for_workspace(&curr_workspace))
+}
+
+// `SourceId::as_url` renders the same `<kind>+<url>` form Cargo writes
+// into a lockfile's `source` field, so we can use it directly as
+// the other half of the `(name, source)` key `resolved_versions_from_lock`
+// groups by. Path dependencies have no `source` line in the lockfile at
+// all, so they key on `None` instead.
+//
+// A git source's lockfile entry carries the resolved commit as a `#<rev>`
+// fragment (e.g. `git+https://.../repo?branch=main#abc123`), but the
+// manifest's own `SourceId` has no precise revision set and so renders
+// without one. Strip the fragment so both sides key on the repo URL and
+// query alone, or a git dependency would always miss the lockfile lookup
+// and be reported as permanently outdated.
+fn dep_source_string(source_id: &SourceId) -> Option<String> {
+    if source_id.is_path() {
+        None
+    } else {
+        Some(strip_precise_fragment(&source_id.as_url().to_string()))
+    }
+}
+
+fn strip_precise_fragment(source: &str) -> String {
+    match source.split_once('#') {
+        Some((without_fragment, _)) => without_fragment.to_string(),
+        None => source.to_string(),
+    }
+}
+
+// Reads the workspace's Cargo.lock and returns every version each
+// (crate name, source) pair actually resolved to, so we can tell
+// "declared" apart from "resolved". A lockfile can legitimately carry more
+// than one version of the same crate name (diamond dependencies on
+// semver-incompatible majors), so we keep all of them rather than
+// collapsing to whichever happened to come last in file order.
+type ResolvedLockVersions = HashMap<(String, Option<String>), Vec<Version>>;
+
+fn resolved_versions_from_lock() -> Result<ResolvedLockVersions> {
+    let config = cargo_config()?;
+    let curr_workspace = open_workspace(&config)?;
+
+    let lock_path = curr_workspace.root().join("Cargo.lock");
+    let raw = fs::read_to_string(&lock_path)
+        .with_context(|| format!("Error reading lockfile {}", lock_path.display()))?;
+    let lock: CargoLock = toml::from_str(&raw)
+        .with_context(|| format!("Error parsing lockfile {}", lock_path.display()))?;
+
+    let mut resolved: ResolvedLockVersions = HashMap::new();
+    for package in lock.package {
+        let version = Version::parse(&package.version)
+            .with_context(|| format!("Error parsing locked version for {}", package.name))?;
+        let source = package.source.as_deref().map(strip_precise_fragment);
+        resolved
+            .entry((package.name.clone(), source))
+            .or_default()
+            .push(version);
+    }
+    Ok(resolved)
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct UpgradedDependency {
+    manifest_path: PathBuf,
+    dependency_name: String,
+    old_requirement: String,
+    new_requirement: String,
+}
+
+#[derive(Serialize)]
+struct UpgradeSummary {
+    upgraded: Vec<UpgradedDependency>,
+}
+
+impl fmt::Display for UpgradeSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.upgraded.is_empty() {
+            return writeln!(f, "No dependency version requirements needed upgrading.");
+        }
+
+        let mut output_string = String::new();
+        for upgrade in self.upgraded.iter() {
+            output_string += &format!(
+                "{}: {} {} -> {}\n",
+                upgrade.manifest_path.display(),
+                upgrade.dependency_name,
+                upgrade.old_requirement,
+                upgrade.new_requirement
+            );
+        }
+        write!(f, "{}", output_string)
+    }
+}
+
+// Rewrites the version requirement of every outdated dependency in-place,
+// using `toml_edit` so comments/formatting in the manifest survive. Deps
+// named in `pinned` are skipped entirely.
+fn apply_upgrades(
+    outdated: &CrateOutdated,
+    manifest_paths: &[PathBuf],
+    pinned: &HashSet<String>,
+) -> Result<UpgradeSummary> {
+    let outdated_by_name: HashMap<&str, &str> = outdated
+        .outdated
+        .values()
+        .flatten()
+        .map(|dep| (dep.dependency_name.as_str(), dep.latest.as_str()))
+        .collect();
+
+    let mut summary = UpgradeSummary {
+        upgraded: Vec::new(),
+    };
+
+    for manifest_path in manifest_paths {
+        let raw = fs::read_to_string(manifest_path)
+            .with_context(|| format!("Error reading manifest {}", manifest_path.display()))?;
+        let mut doc = raw
+            .parse::<Document>()
+            .with_context(|| format!("Error parsing manifest {}", manifest_path.display()))?;
+
+        let mut changed = false;
+        for table_name in DEPENDENCY_TABLES.iter() {
+            let table = match doc.get_mut(table_name).and_then(|item| item.as_table_like_mut()) {
+                Some(table) => table,
+                None => continue,
+            };
+
+            let dep_names: Vec<String> = table.iter().map(|(k, _)| k.to_string()).collect();
+            for dep_name in dep_names {
+                if pinned.contains(&dep_name) {
+                    continue;
+                }
+
+                let latest = match outdated_by_name.get(dep_name.as_str()) {
+                    Some(latest) => *latest,
+                    None => continue,
+                };
+
+                let old_requirement = match table.get(&dep_name) {
+                    Some(item) if item.is_str() => item.as_str().unwrap().to_string(),
+                    Some(item) => match item.get("version").and_then(|v| v.as_str()) {
+                        Some(version) => version.to_string(),
+                        None => continue,
+                    },
+                    None => continue,
+                };
+
+                let new_requirement = format!("^{}", latest);
+
+                // a bare requirement ("1.2.5") and an explicit caret
+                // ("^1.2.5") are semver-equivalent -- compare the parsed
+                // `VersionReq`s rather than the raw strings so we don't
+                // report a purely cosmetic reformat as an upgrade
+                let unchanged = match VersionReq::parse(&old_requirement) {
+                    Ok(old_req) => Some(&old_req) == VersionReq::parse(&new_requirement).ok().as_ref(),
+                    Err(_) => old_requirement == new_requirement,
+                };
+                if unchanged {
+                    continue;
+                }
+
+                let is_inline_string = table.get(&dep_name).map(|item| item.is_str()).unwrap_or(false);
+                if is_inline_string {
+                    table.insert(&dep_name, value(new_requirement.clone()));
+                } else if let Some(dep_table) = table.get_mut(&dep_name).and_then(|item| item.as_table_like_mut()) {
+                    dep_table.insert("version", value(new_requirement.clone()));
+                } else {
+                    continue;
+                }
+
+                summary.upgraded.push(UpgradedDependency {
+                    manifest_path: manifest_path.clone(),
+                    dependency_name: dep_name,
+                    old_requirement,
+                    new_requirement,
+                });
+                changed = true;
+            }
+        }
+
+        if changed {
+            fs::write(manifest_path, doc.to_string())
+                .with_context(|| format!("Error writing manifest {}", manifest_path.display()))?;
+        }
+    }
+
+    Ok(summary)
+}
+
+// Builds the outdated-dependency report without printing anything, so
+// `main` can render it either as a human tree or as JSON.
+fn build_report(
+    deps: &HashMap<String, HashSet<Dep>>,
+    resolved: &HashMap<(String, Option<String>), Vec<Version>>,
+    args: &Args,
+    cache: &CacheOptions,
+) -> CrateOutdated {
     //let outdated = Arc::new(Mutex::new(CrateOutdated::new()));
     let mut outdated = CrateOutdated::new();
     let x: Vec<(String, OutdatedDependency)> = deps
@@ -283,18 +1006,45 @@ fn main() -> Result<()> {
             let mut y: Vec<Option<(String, OutdatedDependency)>> = (*crate_deps)
                 .par_iter()
                 .map(|dep| {
-                    if !dep.source_id.is_path() {
-                        let dep_latest = get_latest_from_repo(dep.name.clone()).ok()?;
-                        if !is_up_to_date(&dep.version_req, &dep_latest.version) {
-                            let this_dep = OutdatedDependency {
-                                dependency_name: dep.name.to_string(),
-                                version_in_toml: dep.version_req.to_string(),
-                                latest_version: dep_latest.version.to_string(),
-                            };
-                            Some((crate_name.to_string(), this_dep))
-                        } else {
-                            None
-                        }
+                    let channel = tracked_channel(&dep.version_req, args.channel_override.as_deref());
+                    let dep_latest = resolve_latest(dep, &channel, cache).ok()?;
+
+                    // no release could be found on this channel -- skip the
+                    // dependency entirely rather than reporting (or letting
+                    // `--upgrade` rewrite toward) a fake `0.0.0`
+                    let latest_version = dep_latest.version.clone()?;
+                    let compatible = dep_latest
+                        .compatible_version
+                        .clone()
+                        .unwrap_or_else(|| latest_version.clone());
+                    // a single (name, source) pair can legitimately resolve
+                    // to more than one version in the same lockfile (diamond
+                    // dependencies on semver-incompatible majors), so pick
+                    // whichever locked version actually satisfies this
+                    // dependency's declared requirement
+                    let resolved_version = resolved
+                        .get(&(dep.name.clone(), dep_source_string(&dep.source_id)))
+                        .and_then(|versions| versions.iter().filter(|v| dep.version_req.matches(v)).max())
+                        .cloned();
+
+                    let this_dep = OutdatedDependency {
+                        dependency_name: dep.name.to_string(),
+                        version_in_toml: dep.version_req.to_string(),
+                        resolved: resolved_version
+                            .as_ref()
+                            .map(|v| v.to_string())
+                            .unwrap_or_else(|| "unknown".to_string()),
+                        compatible: compatible.to_string(),
+                        latest: latest_version.to_string(),
+                    };
+
+                    // if we can't resolve a locked version, err on the
+                    // side of reporting it so it isn't silently hidden
+                    let up_to_date = resolved_version.as_ref() == Some(&compatible)
+                        && this_dep.is_up_to_date();
+
+                    if !up_to_date {
+                        Some((crate_name.to_string(), this_dep))
                     } else {
                         None
                     }
@@ -309,26 +1059,325 @@ fn main() -> Result<()> {
         .collect();
 
     for (crate_name, out_dep) in x.iter() {
-    
-    //x.par_iter().for_each(|(crate_name, out_dep)| {
-    //    let mut outdated_map = outdated
-    //    .lock()
-    //    .unwrap();
-        //let crate_map = outdated_map
         let crate_map = outdated
             .outdated
             .entry(crate_name.into())
-            .or_insert(Vec::new());
+            .or_default();
         crate_map.push(out_dep.clone());
-    }//);
+    }
 
-    //if outdated.lock().unwrap().outdated.is_empty() {
-    if outdated.outdated.is_empty() {
-        println!("All dependencies are up-to-date!");
-    } else {
-        //println!("{}", outdated.lock().unwrap());
-        println!("{}", outdated);
+    outdated
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    if args.clear_cache {
+        if let Some(cache_dir) = default_cache_dir() {
+            clear_cache(&cache_dir)?;
+            println!("Cleared cache at {}", cache_dir.display());
+        }
+        return Ok(());
+    }
+
+    let cache = args.cache_options();
+    let deps = create_cargo_manifest()?;
+    let resolved = resolved_versions_from_lock().unwrap_or_default();
+
+    let outdated = build_report(&deps, &resolved, &args, &cache);
+
+    match args.format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&outdated.outdated)?);
+        }
+        OutputFormat::Human => {
+            if outdated.outdated.is_empty() {
+                println!("All dependencies are up-to-date!");
+            } else {
+                println!("{}", outdated);
+            }
+        }
+    }
+
+    if args.upgrade {
+        let pinned = match &args.pinned_file {
+            Some(path) => load_pinned_crates(path)?,
+            None => HashSet::new(),
+        };
+
+        let manifest_paths = gather_manifest_paths()?;
+        let summary = apply_upgrades(&outdated, &manifest_paths, &pinned)?;
+
+        match args.format {
+            OutputFormat::Json => println!("{}", serde_json::to_string(&summary.upgraded)?),
+            OutputFormat::Human => println!("{}", summary),
+        }
+    }
+
+    if !outdated.outdated.is_empty() && !args.exit_zero {
+        std::process::exit(1);
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channel_for_version_stable() {
+        assert_eq!(channel_for_version(&Version::parse("1.2.3").unwrap()), "stable");
+    }
+
+    #[test]
+    fn channel_for_version_prerelease() {
+        assert_eq!(channel_for_version(&Version::parse("1.0.0-beta.2").unwrap()), "beta");
+    }
+
+    #[test]
+    fn tracked_channel_prefers_override() {
+        let version_req = VersionReq::parse("^1.0.0").unwrap();
+        assert_eq!(tracked_channel(&version_req, Some("nightly")), "nightly");
+    }
+
+    #[test]
+    fn tracked_channel_reads_comparator_prerelease() {
+        let version_req = VersionReq::parse("^1.0.0-beta").unwrap();
+        assert_eq!(tracked_channel(&version_req, None), "beta");
+    }
+
+    #[test]
+    fn tracked_channel_defaults_to_stable() {
+        let version_req = VersionReq::parse("*").unwrap();
+        assert_eq!(tracked_channel(&version_req, None), "stable");
+    }
+
+    #[test]
+    fn strip_precise_fragment_removes_git_revision() {
+        assert_eq!(
+            strip_precise_fragment("git+https://example.com/repo?branch=main#abc123"),
+            "git+https://example.com/repo?branch=main"
+        );
+    }
+
+    #[test]
+    fn strip_precise_fragment_leaves_registry_source_untouched() {
+        assert_eq!(
+            strip_precise_fragment("registry+https://github.com/rust-lang/crates.io-index"),
+            "registry+https://github.com/rust-lang/crates.io-index"
+        );
+    }
+
+    #[test]
+    fn sparse_index_path_buckets_by_name_length() {
+        assert_eq!(sparse_index_path("a"), "1/a");
+        assert_eq!(sparse_index_path("ab"), "2/ab");
+        assert_eq!(sparse_index_path("abc"), "3/a/abc");
+        assert_eq!(sparse_index_path("Serde"), "se/rd/serde");
+    }
+
+    #[test]
+    fn parse_sparse_index_body_skips_blank_lines() {
+        let body = "\
+{\"vers\":\"1.0.0\",\"yanked\":false}
+
+{\"vers\":\"1.1.0\",\"yanked\":true}
+";
+        let versions = parse_sparse_index_body(body).unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].num, "1.0.0");
+        assert!(!versions[0].yanked);
+        assert_eq!(versions[1].num, "1.1.0");
+        assert!(versions[1].yanked);
+    }
+
+    #[test]
+    fn parse_sparse_index_body_rejects_malformed_entry() {
+        assert!(parse_sparse_index_body("not json").is_err());
+    }
+
+    fn outdated_dep(name: &str, latest: &str) -> OutdatedDependency {
+        OutdatedDependency {
+            dependency_name: name.to_string(),
+            version_in_toml: "irrelevant".to_string(),
+            resolved: "irrelevant".to_string(),
+            compatible: "irrelevant".to_string(),
+            latest: latest.to_string(),
+        }
+    }
+
+    fn scratch_manifest_path(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "outdated2-test-manifest-{}-{}.toml",
+            name,
+            std::process::id()
+        ));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn apply_upgrades_rewrites_outdated_requirement() {
+        let manifest_path = scratch_manifest_path(
+            "upgrade",
+            "[dependencies]\nserde = \"1.0.0\"\n",
+        );
+
+        let mut outdated = CrateOutdated::new();
+        outdated
+            .outdated
+            .insert("serde".to_string(), vec![outdated_dep("serde", "1.2.0")]);
+
+        let summary =
+            apply_upgrades(&outdated, std::slice::from_ref(&manifest_path), &HashSet::new()).unwrap();
+
+        let rewritten = fs::read_to_string(&manifest_path).unwrap();
+        fs::remove_file(&manifest_path).unwrap();
+
+        assert_eq!(summary.upgraded.len(), 1);
+        assert_eq!(summary.upgraded[0].new_requirement, "^1.2.0");
+        assert!(rewritten.contains("serde = \"^1.2.0\""));
+    }
+
+    #[test]
+    fn apply_upgrades_leaves_semver_equivalent_requirement_untouched() {
+        let manifest_path = scratch_manifest_path(
+            "equivalent",
+            "[dependencies]\nserde = \"1.2.0\"\n",
+        );
+
+        let mut outdated = CrateOutdated::new();
+        outdated
+            .outdated
+            .insert("serde".to_string(), vec![outdated_dep("serde", "1.2.0")]);
+
+        let summary =
+            apply_upgrades(&outdated, std::slice::from_ref(&manifest_path), &HashSet::new()).unwrap();
+
+        let untouched = fs::read_to_string(&manifest_path).unwrap();
+        fs::remove_file(&manifest_path).unwrap();
+
+        assert!(summary.upgraded.is_empty());
+        assert!(untouched.contains("serde = \"1.2.0\""));
+    }
+
+    #[test]
+    fn apply_upgrades_skips_pinned_dependency() {
+        let manifest_path = scratch_manifest_path(
+            "pinned",
+            "[dependencies]\nserde = \"1.0.0\"\n",
+        );
+
+        let mut outdated = CrateOutdated::new();
+        outdated
+            .outdated
+            .insert("serde".to_string(), vec![outdated_dep("serde", "1.2.0")]);
+        let pinned: HashSet<String> = ["serde".to_string()].into_iter().collect();
+
+        let summary = apply_upgrades(&outdated, std::slice::from_ref(&manifest_path), &pinned).unwrap();
+
+        let untouched = fs::read_to_string(&manifest_path).unwrap();
+        fs::remove_file(&manifest_path).unwrap();
+
+        assert!(summary.upgraded.is_empty());
+        assert!(untouched.contains("serde = \"1.0.0\""));
+    }
+
+    fn scratch_cache_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("outdated2-test-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn cache_round_trips_within_ttl() {
+        let cache_dir = scratch_cache_dir("round-trip");
+        let versions = vec![VersionEntry {
+            num: "1.2.3".to_string(),
+            yanked: false,
+        }];
+
+        write_to_cache(&cache_dir, "crates-io", "serde", &versions).unwrap();
+        let read_back = read_from_cache(&cache_dir, "crates-io", "serde", Duration::from_secs(60));
+
+        fs::remove_dir_all(&cache_dir).unwrap();
+        assert_eq!(read_back.unwrap()[0].num, "1.2.3");
+    }
+
+    #[test]
+    fn cache_expires_past_ttl() {
+        let cache_dir = scratch_cache_dir("ttl-expiry");
+        fs::create_dir_all(&cache_dir).unwrap();
+
+        let stale = serde_json::json!({
+            "fetched_at_secs": 0,
+            "versions": [{"num": "1.2.3", "yanked": false}],
+        });
+        fs::write(cache_file_path(&cache_dir, "crates-io", "serde"), stale.to_string()).unwrap();
+
+        let read_back = read_from_cache(&cache_dir, "crates-io", "serde", Duration::from_secs(60));
+
+        fs::remove_dir_all(&cache_dir).unwrap();
+        assert!(read_back.is_none());
+    }
+
+    #[test]
+    fn cache_miss_for_unwritten_key_is_none() {
+        let cache_dir = scratch_cache_dir("miss");
+        assert!(read_from_cache(&cache_dir, "crates-io", "does-not-exist", Duration::from_secs(60)).is_none());
+    }
+
+    fn entry(num: &str, yanked: bool) -> VersionEntry {
+        VersionEntry {
+            num: num.to_string(),
+            yanked,
+        }
+    }
+
+    #[test]
+    fn select_latest_skips_yanked_and_other_channels() {
+        let versions = vec![
+            entry("1.0.0", false),
+            entry("1.1.0", true),
+            entry("1.2.0", false),
+            entry("2.0.0-beta.1", false),
+        ];
+        let version_req = VersionReq::parse("^1.0.0").unwrap();
+
+        let latest = select_latest(&versions, "stable", &version_req).unwrap();
+        assert_eq!(latest.version, Some(Version::parse("1.2.0").unwrap()));
+        assert_eq!(latest.compatible_version, Some(Version::parse("1.2.0").unwrap()));
+    }
+
+    #[test]
+    fn select_latest_unknown_channel_is_none() {
+        let versions = vec![entry("1.0.0", false)];
+        let version_req = VersionReq::parse("^1.0.0").unwrap();
+
+        let latest = select_latest(&versions, "beta", &version_req).unwrap();
+        assert_eq!(latest.version, None);
+    }
+
+    #[test]
+    fn outdated_dependency_is_up_to_date() {
+        let dep = OutdatedDependency {
+            dependency_name: "serde".to_string(),
+            version_in_toml: "^1.0.0".to_string(),
+            resolved: "1.2.0".to_string(),
+            compatible: "1.2.0".to_string(),
+            latest: "1.2.0".to_string(),
+        };
+        assert!(dep.is_up_to_date());
+    }
+
+    #[test]
+    fn outdated_dependency_is_not_up_to_date() {
+        let dep = OutdatedDependency {
+            dependency_name: "serde".to_string(),
+            version_in_toml: "^1.0.0".to_string(),
+            resolved: "1.2.0".to_string(),
+            compatible: "1.3.0".to_string(),
+            latest: "1.3.0".to_string(),
+        };
+        assert!(!dep.is_up_to_date());
+    }
+}